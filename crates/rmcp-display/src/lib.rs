@@ -1,11 +1,16 @@
+use std::time::Duration;
+
+use ddc_hi::{Ddc, Display as DdcDisplay};
 use display_info::DisplayInfo;
 use rmcp::{
-    handler::server::{router::tool::ToolRouter, ServerHandler, wrapper::Parameters},
+    handler::server::{router::tool::ToolRouter, NotificationContext, ServerHandler, wrapper::Parameters},
     model::*,
-    ErrorData as McpError,
+    service::RoleServer,
+    ErrorData as McpError, Peer,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 
 /// Parameters for get_display_at_point
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -23,6 +28,223 @@ pub struct NameParams {
     pub name: String,
 }
 
+/// Parameters for setting a monitor brightness level
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SetBrightnessParams {
+    #[schemars(description = "Display name to target")]
+    pub name: String,
+    #[schemars(description = "Brightness value to set (typically 0-100)")]
+    pub value: u16,
+}
+
+/// Parameters for selecting a monitor input source
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SetInputSourceParams {
+    #[schemars(description = "Display name to target")]
+    pub name: String,
+    #[schemars(description = "Input source VCP value (e.g. 0x0F DisplayPort-1, 0x11 HDMI-1, 0x01 VGA-1)")]
+    pub source: u16,
+}
+
+/// Decoded contents of an EDID 128-byte base block.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct EdidInfo {
+    #[schemars(description = "3-letter PNP manufacturer id")]
+    pub manufacturer: String,
+    #[schemars(description = "Vendor product code")]
+    pub product_code: u16,
+    #[schemars(description = "Serial number from the EDID header")]
+    pub serial_number: u32,
+    #[schemars(description = "Week of manufacture (1-54, 0 if unspecified)")]
+    pub manufacture_week: u8,
+    #[schemars(description = "Year of manufacture")]
+    pub manufacture_year: u16,
+    #[schemars(description = "EDID version.revision, e.g. \"1.4\"")]
+    pub edid_version: String,
+    #[schemars(description = "Monitor name from descriptor 0xFC, if present")]
+    pub monitor_name: Option<String>,
+    #[schemars(description = "Serial string from descriptor 0xFF, if present")]
+    pub serial_string: Option<String>,
+}
+
+/// Parse a 128-byte EDID base block into its identity fields.
+///
+/// Validates the fixed `00 FF FF FF FF FF FF 00` header and the trailing
+/// checksum (all 128 bytes sum to 0 mod 256) before decoding. Returns a
+/// descriptive error for anything that is not a well-formed base block.
+fn parse_edid(bytes: &[u8]) -> Result<EdidInfo, String> {
+    if bytes.len() < 128 {
+        return Err(format!("EDID too short: {} bytes, need 128", bytes.len()));
+    }
+    const HEADER: [u8; 8] = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+    if bytes[..8] != HEADER {
+        return Err("invalid EDID header".to_string());
+    }
+    if bytes[..128].iter().fold(0u8, |acc, b| acc.wrapping_add(*b)) != 0 {
+        return Err("EDID checksum mismatch".to_string());
+    }
+
+    // Manufacturer id: three 5-bit letters packed big-endian across bytes 8-9.
+    let packed = u16::from_be_bytes([bytes[8], bytes[9]]);
+    let letter = |shift: u16| ((((packed >> shift) & 0x1F) as u8) + b'A' - 1) as char;
+    let manufacturer: String = [letter(10), letter(5), letter(0)].iter().collect();
+
+    let product_code = u16::from_le_bytes([bytes[10], bytes[11]]);
+    let serial_number = u32::from_le_bytes([bytes[12], bytes[13], bytes[14], bytes[15]]);
+    let manufacture_week = bytes[16];
+    let manufacture_year = bytes[17] as u16 + 1990;
+    let edid_version = format!("{}.{}", bytes[18], bytes[19]);
+
+    // Walk the four 18-byte descriptor blocks starting at offset 54.
+    let mut monitor_name = None;
+    let mut serial_string = None;
+    for i in 0..4 {
+        let d = &bytes[54 + i * 18..54 + i * 18 + 18];
+        if d[0] != 0 || d[1] != 0 {
+            continue; // detailed timing descriptor, not a display descriptor
+        }
+        let text = || {
+            let raw: String = d[5..18].iter().take_while(|&&b| b != 0x0A).map(|&b| b as char).collect();
+            raw.trim().to_string()
+        };
+        match d[3] {
+            0xFC => monitor_name = Some(text()),
+            0xFF => serial_string = Some(text()),
+            _ => {}
+        }
+    }
+
+    Ok(EdidInfo {
+        manufacturer,
+        product_code,
+        serial_number,
+        manufacture_week,
+        manufacture_year,
+        edid_version,
+        monitor_name,
+        serial_string,
+    })
+}
+
+/// A single resolution + refresh-rate combination a display can switch to.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DisplayMode {
+    #[schemars(description = "Horizontal resolution in pixels")]
+    pub width: u32,
+    #[schemars(description = "Vertical resolution in pixels")]
+    pub height: u32,
+    #[schemars(description = "Vertical refresh rate in Hz")]
+    pub refresh: f32,
+    #[schemars(description = "Whether this mode is the one currently active on the display")]
+    pub active: bool,
+}
+
+/// Parameters for get_displays_overlapping_rect
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct RectParams {
+    #[schemars(description = "Left edge in global coordinates")]
+    pub x: i32,
+    #[schemars(description = "Top edge in global coordinates")]
+    pub y: i32,
+    #[schemars(description = "Rectangle width")]
+    pub w: i32,
+    #[schemars(description = "Rectangle height")]
+    pub h: i32,
+}
+
+/// Direction for get_adjacent_display
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// Parameters for get_adjacent_display
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AdjacentParams {
+    #[schemars(description = "Reference display name")]
+    pub name: String,
+    #[schemars(description = "Direction to search from the reference display")]
+    pub direction: Direction,
+}
+
+/// VCP feature codes used over DDC/CI.
+const VCP_BRIGHTNESS: u8 = 0x10;
+const VCP_CONTRAST: u8 = 0x12;
+const VCP_INPUT_SOURCE: u8 = 0x60;
+
+/// A minimal, comparable snapshot of one display's configuration.
+///
+/// We keep only the fields whose change constitutes a reconfiguration so the
+/// watcher can diff two snapshots cheaply.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct DisplaySnapshot {
+    name: String,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    scale_factor: f32,
+    frequency: f32,
+    is_primary: bool,
+}
+
+impl DisplaySnapshot {
+    fn capture() -> Vec<DisplaySnapshot> {
+        DisplayInfo::all()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|d| DisplaySnapshot {
+                name: if d.friendly_name.is_empty() { d.name } else { d.friendly_name },
+                x: d.x,
+                y: d.y,
+                width: d.width,
+                height: d.height,
+                scale_factor: d.scale_factor,
+                frequency: d.frequency,
+                is_primary: d.is_primary,
+            })
+            .collect()
+    }
+}
+
+/// Diff two display snapshots into added/removed/modified sets.
+fn diff_snapshots(
+    prev: &[DisplaySnapshot],
+    next: &[DisplaySnapshot],
+) -> (Vec<String>, Vec<String>, Vec<String>) {
+    let added = next
+        .iter()
+        .filter(|n| !prev.iter().any(|p| p.name == n.name))
+        .map(|n| n.name.clone())
+        .collect();
+    let removed = prev
+        .iter()
+        .filter(|p| !next.iter().any(|n| n.name == p.name))
+        .map(|p| p.name.clone())
+        .collect();
+    let modified = next
+        .iter()
+        .filter(|n| prev.iter().any(|p| p.name == n.name && p != *n))
+        .map(|n| n.name.clone())
+        .collect();
+    (added, removed, modified)
+}
+
+/// Overlap area in px² between two rectangles given as `(x, y, w, h)`.
+fn rect_intersection_area(a: (i32, i32, i32, i32), b: (i32, i32, i32, i32)) -> i64 {
+    let left = a.0.max(b.0);
+    let top = a.1.max(b.1);
+    let right = (a.0 + a.2).min(b.0 + b.2);
+    let bottom = (a.1 + a.3).min(b.1 + b.3);
+    let iw = (right - left).max(0) as i64;
+    let ih = (bottom - top).max(0) as i64;
+    iw * ih
+}
+
 #[derive(Debug)]
 pub struct DisplayServer {
     pub tool_router: ToolRouter<Self>,
@@ -41,7 +263,7 @@ impl DisplayServer {
         }
     }
 
-    fn format_single_display(d: &DisplayInfo) -> String {
+    fn format_single_display(d: &DisplayInfo, work: Option<(i32, i32, u32, u32)>) -> String {
         let mut result = String::new();
 
         // Header with name and primary indicator
@@ -81,9 +303,83 @@ impl DisplayServer {
             result.push_str(&format!("  Rotation: {}°\n", d.rotation as i32));
         }
 
+        // Usable work area (full bounds minus reserved chrome), when available
+        // and actually smaller than the full rectangle.
+        if let Some((wx, wy, ww, wh)) = work {
+            if wx != d.x || wy != d.y || ww != d.width || wh != d.height {
+                result.push_str(&format!("  Work area: {}x{} at ({}, {})\n", ww, wh, wx, wy));
+            }
+        }
+
         result
     }
 
+    /// The current desktop's usable region `(x, y, w, h)` across the whole
+    /// virtual screen, read once from the window manager's `_NET_WORKAREA`
+    /// (EWMH) property. Callers intersect it with each display's bounds.
+    ///
+    /// Returns an error when the property is unavailable rather than pretending
+    /// the full rectangle is usable.
+    #[cfg(target_os = "linux")]
+    fn net_workarea() -> Result<(i32, i32, i32, i32), McpError> {
+        use x11rb::connection::Connection;
+        use x11rb::protocol::xproto::{AtomEnum, ConnectionExt};
+
+        let err = |msg: String| McpError::internal_error(msg, None);
+        let (conn, screen_num) =
+            x11rb::connect(None).map_err(|e| err(format!("Failed to connect to X server: {}", e)))?;
+        let root = conn.setup().roots[screen_num].root;
+        let atom = conn
+            .intern_atom(false, b"_NET_WORKAREA")
+            .and_then(|c| c.reply().map_err(Into::into))
+            .map_err(|e| err(format!("Failed to resolve _NET_WORKAREA: {}", e)))?
+            .atom;
+        // _NET_WORKAREA holds four CARDINALs per desktop; select the entry of
+        // the desktop named by _NET_CURRENT_DESKTOP (desktop 0 if unset).
+        let current = conn
+            .intern_atom(false, b"_NET_CURRENT_DESKTOP")
+            .ok()
+            .and_then(|c| c.reply().ok())
+            .and_then(|a| conn.get_property(false, root, a.atom, AtomEnum::CARDINAL, 0, 1).ok())
+            .and_then(|c| c.reply().ok())
+            .and_then(|r| r.value32().and_then(|mut i| i.next()))
+            .unwrap_or(0);
+        let reply = conn
+            .get_property(false, root, atom, AtomEnum::CARDINAL, current.saturating_mul(4), 4)
+            .and_then(|c| c.reply().map_err(Into::into))
+            .map_err(|e| err(format!("Failed to read _NET_WORKAREA: {}", e)))?;
+        let vals: Vec<u32> = reply.value32().map(|i| i.collect()).unwrap_or_default();
+        if vals.len() < 4 {
+            return Err(err("_NET_WORKAREA is not published by the window manager".to_string()));
+        }
+        Ok((vals[0] as i32, vals[1] as i32, vals[2] as i32, vals[3] as i32))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn net_workarea() -> Result<(i32, i32, i32, i32), McpError> {
+        // Windows: GetMonitorInfo.rcWork / SPI_GETWORKAREA. macOS: visibleFrame.
+        Err(McpError::internal_error(
+            "Work-area queries are not yet implemented on this platform",
+            None,
+        ))
+    }
+
+    /// Clip a display's bounds to the desktop work area.
+    fn clip_to_workarea(d: &DisplayInfo, area: (i32, i32, i32, i32)) -> (i32, i32, u32, u32) {
+        let (ax, ay, aw, ah) = area;
+        let left = d.x.max(ax);
+        let top = d.y.max(ay);
+        let right = (d.x + d.width as i32).min(ax + aw);
+        let bottom = (d.y + d.height as i32).min(ay + ah);
+        (left, top, (right - left).max(0) as u32, (bottom - top).max(0) as u32)
+    }
+
+    /// Usable work area for a single display, as full bounds minus reserved
+    /// chrome. Convenience wrapper around [`net_workarea`] + [`clip_to_workarea`].
+    fn work_area(d: &DisplayInfo) -> Result<(i32, i32, u32, u32), McpError> {
+        Ok(Self::clip_to_workarea(d, Self::net_workarea()?))
+    }
+
     fn format_display_info(displays: &[DisplayInfo]) -> String {
         let mut result = String::from("Display Information:\n\n");
 
@@ -92,15 +388,317 @@ impl DisplayServer {
             return result;
         }
 
+        // Query the work area once and intersect it per display, rather than
+        // opening a fresh X connection for every monitor in the loop.
+        let area = Self::net_workarea().ok();
         for (i, d) in displays.iter().enumerate() {
+            let work = area.map(|a| Self::clip_to_workarea(d, a));
             result.push_str(&format!("Display {}: ", i + 1));
-            result.push_str(&Self::format_single_display(d));
+            result.push_str(&Self::format_single_display(d, work));
             result.push('\n');
         }
 
         result.push_str(&format!("Total displays: {}\n", displays.len()));
         result
     }
+
+    /// Spawn the background display-hotplug watcher.
+    ///
+    /// Periodically snapshots `DisplayInfo::all()`, diffs it against the previous
+    /// snapshot and emits a `displays/changed` notification describing the
+    /// added/removed/modified displays. A burst of reconfigure events is
+    /// debounced into a single notification by waiting for the layout to settle
+    /// before emitting.
+    fn spawn_watcher(peer: Peer<RoleServer>) {
+        tokio::spawn(async move {
+            const POLL: Duration = Duration::from_secs(2);
+            const SETTLE: Duration = Duration::from_millis(500);
+
+            let mut previous = DisplaySnapshot::capture();
+            loop {
+                tokio::time::sleep(POLL).await;
+                let mut current = DisplaySnapshot::capture();
+                if current == previous {
+                    continue;
+                }
+
+                // Debounce: keep re-sampling until the layout stops changing so a
+                // burst of reconfigure events collapses into one notification.
+                loop {
+                    tokio::time::sleep(SETTLE).await;
+                    let settled = DisplaySnapshot::capture();
+                    if settled == current {
+                        break;
+                    }
+                    current = settled;
+                }
+
+                let (added, removed, modified) = diff_snapshots(&previous, &current);
+                if added.is_empty() && removed.is_empty() && modified.is_empty() {
+                    previous = current;
+                    continue;
+                }
+
+                let params = json!({
+                    "added": added,
+                    "removed": removed,
+                    "modified": modified,
+                });
+                if peer
+                    .send_notification(
+                        Notification::new("displays/changed".to_string(), params).into(),
+                    )
+                    .await
+                    .is_err()
+                {
+                    // Client has gone away; stop polling rather than leaking a
+                    // perpetual watcher task targeting a dead peer.
+                    break;
+                }
+
+                previous = current;
+            }
+        });
+    }
+
+    /// Intersection area between a display and an arbitrary rectangle, in px².
+    fn intersection_area(d: &DisplayInfo, x: i32, y: i32, w: i32, h: i32) -> i64 {
+        rect_intersection_area((d.x, d.y, d.width as i32, d.height as i32), (x, y, w, h))
+    }
+
+    /// Locate the DDC/CI handle for the display the user named.
+    ///
+    /// `ddc-hi` enumerates monitors independently of `display_info`, and the
+    /// names users obtain from the other tools (OS connector / friendly names)
+    /// match neither ddc-hi's backend `id` (an i2c bus id) nor the EDID model
+    /// string. We therefore bridge through EDID identity: resolve the named
+    /// display's EDID via [`read_edid_bytes`]/[`parse_edid`] (the chunk0-2
+    /// parser) and match the DDC handle on manufacturer + serial number. When
+    /// the EDID cannot be resolved we fall back to comparing the name against
+    /// ddc-hi's own id/model/serial. Returns a clear error when no handle
+    /// matches or the monitor does not speak DDC/CI.
+    fn find_ddc_display(name: &str) -> Result<DdcDisplay, McpError> {
+        // Target EDID identity derived from the DisplayInfo name, if available.
+        let target = Self::read_edid_bytes(name)
+            .ok()
+            .and_then(|bytes| parse_edid(&bytes).ok())
+            .map(|e| (e.manufacturer.to_lowercase(), e.serial_number));
+
+        let wanted = name.to_lowercase();
+        for mut display in DdcDisplay::enumerate() {
+            // Populate the EDID-derived fields before comparing.
+            let _ = display.update_capabilities();
+            let info = &display.info;
+
+            let matches = match &target {
+                Some((mfr, serial)) => {
+                    info.manufacturer_id
+                        .as_deref()
+                        .is_some_and(|m| m.to_lowercase() == *mfr)
+                        && info.serial == Some(*serial)
+                }
+                // Fallback when no EDID identity could be resolved for `name`.
+                None => {
+                    info.id.to_lowercase() == wanted
+                        || info
+                            .model_name
+                            .as_deref()
+                            .is_some_and(|m| m.to_lowercase() == wanted)
+                        || info
+                            .serial_number
+                            .as_deref()
+                            .is_some_and(|s| s.to_lowercase() == wanted)
+                }
+            };
+            if matches {
+                return Ok(display);
+            }
+        }
+        Err(McpError::internal_error(
+            format!("No DDC/CI-capable display matched '{}'", name),
+            None,
+        ))
+    }
+
+    /// Read the raw EDID bytes for a named display from the OS.
+    #[cfg(target_os = "linux")]
+    fn read_edid_bytes(name: &str) -> Result<Vec<u8>, McpError> {
+        // Each DRM connector exposes its EDID blob at
+        // /sys/class/drm/<connector>/edid; the connector directory name is the
+        // same identifier display_info reports (e.g. "HDMI-A-1").
+        for entry in std::fs::read_dir("/sys/class/drm")
+            .map_err(|e| McpError::internal_error(format!("Failed to enumerate DRM connectors: {}", e), None))?
+            .flatten()
+        {
+            let connector = entry.file_name().to_string_lossy().into_owned();
+            if !connector.ends_with(name) && connector != name {
+                continue;
+            }
+            let edid_path = entry.path().join("edid");
+            if let Ok(bytes) = std::fs::read(&edid_path) {
+                if !bytes.is_empty() {
+                    return Ok(bytes);
+                }
+            }
+        }
+        Err(McpError::internal_error(
+            format!("No EDID blob found for display '{}'", name),
+            None,
+        ))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn read_edid_bytes(_name: &str) -> Result<Vec<u8>, McpError> {
+        // Windows exposes the blob under the monitor's registry key via
+        // SetupAPI, and macOS through IORegistry's "IODisplayEDID" property.
+        Err(McpError::internal_error(
+            "Raw EDID access is not yet implemented on this platform",
+            None,
+        ))
+    }
+
+    fn format_edid(name: &str, edid: &EdidInfo) -> String {
+        let mut result = format!("EDID for {}:\n", name);
+        result.push_str(&format!("  Manufacturer: {}\n", edid.manufacturer));
+        result.push_str(&format!("  Product code: {}\n", edid.product_code));
+        result.push_str(&format!("  Serial: {}\n", edid.serial_number));
+        if edid.manufacture_week > 0 {
+            result.push_str(&format!("  Manufactured: week {} of {}\n", edid.manufacture_week, edid.manufacture_year));
+        } else {
+            result.push_str(&format!("  Manufactured: {}\n", edid.manufacture_year));
+        }
+        result.push_str(&format!("  EDID version: {}\n", edid.edid_version));
+        if let Some(n) = &edid.monitor_name {
+            result.push_str(&format!("  Monitor name: {}\n", n));
+        }
+        if let Some(s) = &edid.serial_string {
+            result.push_str(&format!("  Serial string: {}\n", s));
+        }
+        result
+    }
+
+    /// Vertical refresh rate implied by a XRandR mode's timing.
+    #[cfg(target_os = "linux")]
+    fn mode_refresh(mode: &x11rb::protocol::randr::ModeInfo) -> f32 {
+        use x11rb::protocol::randr::ModeFlag;
+        let mut vtotal = mode.vtotal as f64;
+        // Interlaced modes cover two fields per frame; double-scan modes repeat
+        // each line, so adjust the vertical total accordingly.
+        if u32::from(mode.mode_flags) & u32::from(ModeFlag::DOUBLE_SCAN) != 0 {
+            vtotal *= 2.0;
+        }
+        if u32::from(mode.mode_flags) & u32::from(ModeFlag::INTERLACE) != 0 {
+            vtotal /= 2.0;
+        }
+        let denom = mode.htotal as f64 * vtotal;
+        if denom > 0.0 {
+            (mode.dot_clock as f64 / denom) as f32
+        } else {
+            0.0
+        }
+    }
+
+    /// Enumerate every resolution + refresh-rate mode a named display supports.
+    ///
+    /// Resolved through XRandR (`get_screen_resources` + per-output `get_crtc_info`)
+    /// so each mode carries a real vertical refresh rate and the currently active
+    /// mode is identified from the output's CRTC rather than by resolution alone.
+    /// The display is matched on the XRandR output name, which is the same
+    /// connector name the other tools report.
+    #[cfg(target_os = "linux")]
+    fn enumerate_modes(name: &str) -> Result<Vec<DisplayMode>, McpError> {
+        use x11rb::connection::Connection;
+        use x11rb::protocol::randr::ConnectionExt as _;
+
+        let err = |msg: String| McpError::internal_error(msg, None);
+        let (conn, screen_num) =
+            x11rb::connect(None).map_err(|e| err(format!("Failed to connect to X server: {}", e)))?;
+        let root = conn.setup().roots[screen_num].root;
+        let resources = conn
+            .randr_get_screen_resources(root)
+            .and_then(|c| c.reply().map_err(Into::into))
+            .map_err(|e| err(format!("Failed to query screen resources: {}", e)))?;
+
+        // Index mode id -> timing so output mode lists can be resolved.
+        let mode_by_id: std::collections::HashMap<u32, &x11rb::protocol::randr::ModeInfo> =
+            resources.modes.iter().map(|m| (m.id, m)).collect();
+
+        for &output in &resources.outputs {
+            let info = conn
+                .randr_get_output_info(output, resources.config_timestamp)
+                .and_then(|c| c.reply().map_err(Into::into))
+                .map_err(|e| err(format!("Failed to query output info: {}", e)))?;
+            let output_name = String::from_utf8_lossy(&info.name);
+            if output_name != name {
+                continue;
+            }
+
+            // Resolve the active mode id from the output's CRTC, if enabled.
+            let active_mode = if info.crtc != 0 {
+                conn.randr_get_crtc_info(info.crtc, resources.config_timestamp)
+                    .and_then(|c| c.reply().map_err(Into::into))
+                    .map(|c| c.mode)
+                    .unwrap_or(0)
+            } else {
+                0
+            };
+
+            let mut modes = Vec::new();
+            for &id in &info.modes {
+                if let Some(mode) = mode_by_id.get(&id) {
+                    modes.push(DisplayMode {
+                        width: mode.width as u32,
+                        height: mode.height as u32,
+                        refresh: Self::mode_refresh(mode),
+                        active: id == active_mode,
+                    });
+                }
+            }
+            if modes.is_empty() {
+                return Err(err(format!("Display '{}' reports no modes", name)));
+            }
+            return Ok(modes);
+        }
+
+        Err(err(format!("No display matched '{}'", name)))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn enumerate_modes(_name: &str) -> Result<Vec<DisplayMode>, McpError> {
+        // Windows uses EnumDisplaySettingsEx and macOS CGDisplayCopyAllDisplayModes.
+        Err(McpError::internal_error(
+            "Display mode enumeration is not yet implemented on this platform",
+            None,
+        ))
+    }
+
+    fn get_vcp(name: &str, code: u8, label: &str) -> Result<String, McpError> {
+        let mut display = Self::find_ddc_display(name)?;
+        let value = display.handle.get_vcp_feature(code).map_err(|e| {
+            McpError::internal_error(
+                format!("Failed to read {} from '{}': {}", label, name, e),
+                None,
+            )
+        })?;
+        Ok(format!(
+            "{} for {}: {} (max {})\n",
+            label,
+            name,
+            value.value(),
+            value.maximum()
+        ))
+    }
+
+    fn set_vcp(name: &str, code: u8, value: u16, label: &str) -> Result<String, McpError> {
+        let mut display = Self::find_ddc_display(name)?;
+        display.handle.set_vcp_feature(code, value).map_err(|e| {
+            McpError::internal_error(
+                format!("Failed to set {} on '{}': {}", label, name, e),
+                None,
+            )
+        })?;
+        Ok(format!("Set {} for {} to {}\n", label, name, value))
+    }
 }
 
 #[rmcp::tool_router]
@@ -126,7 +724,7 @@ impl DisplayServer {
         let formatted = format!(
             "Display at ({}, {}):\n{}",
             params.x, params.y,
-            Self::format_single_display(&display)
+            Self::format_single_display(&display, Self::work_area(&display).ok())
         );
 
         Ok(CallToolResult::success(vec![Content::text(formatted)]))
@@ -140,10 +738,213 @@ impl DisplayServer {
         let display = DisplayInfo::from_name(&params.name)
             .map_err(|e| McpError::internal_error(format!("Failed to get display '{}': {}", params.name, e), None))?;
 
-        let formatted = Self::format_single_display(&display);
+        let formatted = Self::format_single_display(&display, Self::work_area(&display).ok());
+
+        Ok(CallToolResult::success(vec![Content::text(formatted)]))
+    }
+
+    #[rmcp::tool(description = "Get a monitor's current and maximum brightness over DDC/CI")]
+    pub async fn get_monitor_brightness(
+        &self,
+        Parameters(params): Parameters<NameParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let formatted = Self::get_vcp(&params.name, VCP_BRIGHTNESS, "Brightness")?;
+        Ok(CallToolResult::success(vec![Content::text(formatted)]))
+    }
+
+    #[rmcp::tool(description = "Set a monitor's brightness over DDC/CI (VCP 0x10)")]
+    pub async fn set_monitor_brightness(
+        &self,
+        Parameters(params): Parameters<SetBrightnessParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let formatted = Self::set_vcp(&params.name, VCP_BRIGHTNESS, params.value, "Brightness")?;
+        Ok(CallToolResult::success(vec![Content::text(formatted)]))
+    }
+
+    #[rmcp::tool(description = "Get a monitor's current and maximum contrast over DDC/CI")]
+    pub async fn get_monitor_contrast(
+        &self,
+        Parameters(params): Parameters<NameParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let formatted = Self::get_vcp(&params.name, VCP_CONTRAST, "Contrast")?;
+        Ok(CallToolResult::success(vec![Content::text(formatted)]))
+    }
+
+    #[rmcp::tool(description = "Select a monitor's active input source over DDC/CI (VCP 0x60)")]
+    pub async fn set_input_source(
+        &self,
+        Parameters(params): Parameters<SetInputSourceParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let formatted = Self::set_vcp(&params.name, VCP_INPUT_SOURCE, params.source, "Input source")?;
+        Ok(CallToolResult::success(vec![Content::text(formatted)]))
+    }
 
+    #[rmcp::tool(description = "Decode a display's raw EDID (manufacturer, model, serial, manufacture date)")]
+    pub async fn get_display_edid(
+        &self,
+        Parameters(params): Parameters<NameParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let bytes = Self::read_edid_bytes(&params.name)?;
+        let edid = parse_edid(&bytes)
+            .map_err(|e| McpError::internal_error(format!("Failed to parse EDID for '{}': {}", params.name, e), None))?;
+        let formatted = Self::format_edid(&params.name, &edid);
         Ok(CallToolResult::success(vec![Content::text(formatted)]))
     }
+
+    #[rmcp::tool(description = "List every resolution and refresh rate a display supports, flagging the active mode")]
+    pub async fn get_display_modes(
+        &self,
+        Parameters(params): Parameters<NameParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let mut modes = Self::enumerate_modes(&params.name)?;
+        // Sort by resolution (largest first) then refresh rate.
+        modes.sort_by(|a, b| {
+            (b.width * b.height)
+                .cmp(&(a.width * a.height))
+                .then(b.refresh.total_cmp(&a.refresh))
+        });
+
+        let mut result = format!("Supported modes for {}:\n", params.name);
+        for m in &modes {
+            let marker = if m.active { " (active)" } else { "" };
+            result.push_str(&format!("  {}x{} @ {:.0}Hz{}\n", m.width, m.height, m.refresh, marker));
+        }
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
+    #[rmcp::tool(description = "Get a display's usable work area (full bounds minus taskbars/docks) by name")]
+    pub async fn get_work_area(
+        &self,
+        Parameters(params): Parameters<NameParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let displays = DisplayInfo::all()
+            .map_err(|e| McpError::internal_error(format!("Failed to get display info: {}", e), None))?;
+        let d = displays
+            .iter()
+            .find(|d| d.name == params.name || d.friendly_name == params.name)
+            .ok_or_else(|| McpError::internal_error(format!("Display '{}' not found", params.name), None))?;
+
+        let (wx, wy, ww, wh) = Self::work_area(d)?;
+        let result = format!(
+            "Work area for {}:\n  Full bounds: {}x{} at ({}, {})\n  Work bounds: {}x{} at ({}, {})\n",
+            params.name, d.width, d.height, d.x, d.y, ww, wh, wx, wy
+        );
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
+    #[rmcp::tool(description = "Get the union bounding rectangle of all displays in global coordinates")]
+    pub async fn get_virtual_desktop_bounds(&self) -> Result<CallToolResult, McpError> {
+        let displays = DisplayInfo::all()
+            .map_err(|e| McpError::internal_error(format!("Failed to get display info: {}", e), None))?;
+        if displays.is_empty() {
+            return Err(McpError::internal_error("No displays detected", None));
+        }
+
+        let left = displays.iter().map(|d| d.x).min().unwrap();
+        let top = displays.iter().map(|d| d.y).min().unwrap();
+        let right = displays.iter().map(|d| d.x + d.width as i32).max().unwrap();
+        let bottom = displays.iter().map(|d| d.y + d.height as i32).max().unwrap();
+
+        let result = format!(
+            "Virtual desktop bounds:\n  Position: ({}, {})\n  Size: {}x{}\n",
+            left,
+            top,
+            right - left,
+            bottom - top
+        );
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
+    #[rmcp::tool(description = "List which displays a window rectangle intersects, with per-display intersection area")]
+    pub async fn get_displays_overlapping_rect(
+        &self,
+        Parameters(params): Parameters<RectParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let displays = DisplayInfo::all()
+            .map_err(|e| McpError::internal_error(format!("Failed to get display info: {}", e), None))?;
+
+        let mut result = format!(
+            "Displays overlapping ({}, {}) {}x{}:\n",
+            params.x, params.y, params.w, params.h
+        );
+        let mut any = false;
+        for d in &displays {
+            let area = Self::intersection_area(d, params.x, params.y, params.w, params.h);
+            if area > 0 {
+                any = true;
+                let name = if d.friendly_name.is_empty() { &d.name } else { &d.friendly_name };
+                result.push_str(&format!("  {}: {} px² overlap\n", name, area));
+            }
+        }
+        if !any {
+            result.push_str("  (none)\n");
+        }
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
+    #[rmcp::tool(description = "Get the nearest display to the left/right/up/down of a reference display")]
+    pub async fn get_adjacent_display(
+        &self,
+        Parameters(params): Parameters<AdjacentParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let displays = DisplayInfo::all()
+            .map_err(|e| McpError::internal_error(format!("Failed to get display info: {}", e), None))?;
+
+        let reference = displays
+            .iter()
+            .find(|d| d.name == params.name || d.friendly_name == params.name)
+            .ok_or_else(|| McpError::internal_error(format!("Display '{}' not found", params.name), None))?;
+
+        // Midpoint of the reference's edge facing the requested direction.
+        let rcx = reference.x as f64 + reference.width as f64 / 2.0;
+        let rcy = reference.y as f64 + reference.height as f64 / 2.0;
+        let (rx, ry) = match params.direction {
+            Direction::Left => (reference.x as f64, rcy),
+            Direction::Right => ((reference.x + reference.width as i32) as f64, rcy),
+            Direction::Up => (rcx, reference.y as f64),
+            Direction::Down => (rcx, (reference.y + reference.height as i32) as f64),
+        };
+
+        let mut best: Option<(&DisplayInfo, f64)> = None;
+        for d in &displays {
+            if std::ptr::eq(d, reference) {
+                continue;
+            }
+            let cx = d.x as f64 + d.width as f64 / 2.0;
+            let cy = d.y as f64 + d.height as f64 / 2.0;
+            // Only consider displays that lie in the requested direction.
+            let in_direction = match params.direction {
+                Direction::Left => cx < rcx,
+                Direction::Right => cx > rcx,
+                Direction::Up => cy < rcy,
+                Direction::Down => cy > rcy,
+            };
+            if !in_direction {
+                continue;
+            }
+            // Midpoint of the candidate's edge facing back toward the reference,
+            // then rank by the directional edge-to-edge distance.
+            let (ex, ey) = match params.direction {
+                Direction::Left => ((d.x + d.width as i32) as f64, cy),
+                Direction::Right => (d.x as f64, cy),
+                Direction::Up => (cx, (d.y + d.height as i32) as f64),
+                Direction::Down => (cx, d.y as f64),
+            };
+            let dist = ((ex - rx).powi(2) + (ey - ry).powi(2)).sqrt();
+            if best.is_none_or(|(_, best_dist)| dist < best_dist) {
+                best = Some((d, dist));
+            }
+        }
+
+        let result = match best {
+            Some((d, _)) => {
+                let name = if d.friendly_name.is_empty() { &d.name } else { &d.friendly_name };
+                format!("Adjacent display: {}\n", name)
+            }
+            None => "No adjacent display in that direction\n".to_string(),
+        };
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
 }
 
 #[rmcp::tool_handler]
@@ -153,9 +954,111 @@ impl ServerHandler for DisplayServer {
             protocol_version: ProtocolVersion::V_2024_11_05,
             capabilities: ServerCapabilities::builder()
                 .enable_tools()
+                // The `displays/changed` watcher emits a server-defined
+                // notification. MCP's capability set (tools/resources/prompts/
+                // logging) has no flag for arbitrary custom notifications, so
+                // there is nothing to advertise here beyond tools; the
+                // notification is sent unsolicited once the client initializes.
                 .build(),
             server_info: Implementation::from_build_env(),
             instructions: Some("Cross-platform display/monitor information server".into()),
         }
     }
+
+    async fn on_initialized(&self, context: NotificationContext<RoleServer>) {
+        // Start watching for hotplug/reconfiguration once the client is ready.
+        Self::spawn_watcher(context.peer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a minimal but valid 128-byte EDID base block with a correct
+    /// checksum so `parse_edid` exercises the happy path.
+    fn sample_edid() -> Vec<u8> {
+        let mut edid = vec![0u8; 128];
+        edid[..8].copy_from_slice(&[0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]);
+        // Manufacturer "ABC" packed as three 5-bit letters, big-endian.
+        let packed: u16 = (1 << 10) | (2 << 5) | 3;
+        edid[8..10].copy_from_slice(&packed.to_be_bytes());
+        edid[10..12].copy_from_slice(&0x1234u16.to_le_bytes()); // product code
+        edid[12..16].copy_from_slice(&0x0102_0304u32.to_le_bytes()); // serial
+        edid[16] = 10; // week
+        edid[17] = 33; // year = 1990 + 33 = 2023
+        edid[18] = 1; // version
+        edid[19] = 4; // revision
+        // Monitor-name descriptor (0xFC) in the first descriptor slot.
+        edid[54..58].copy_from_slice(&[0x00, 0x00, 0x00, 0xFC]);
+        edid[59..66].copy_from_slice(b"TestMon");
+        edid[66] = 0x0A;
+        // Final byte makes the 128 bytes sum to 0 mod 256.
+        let sum = edid[..127].iter().fold(0u8, |acc, b| acc.wrapping_add(*b));
+        edid[127] = 0u8.wrapping_sub(sum);
+        edid
+    }
+
+    fn snap(name: &str, x: i32, y: i32, w: u32, h: u32) -> DisplaySnapshot {
+        DisplaySnapshot {
+            name: name.to_string(),
+            x,
+            y,
+            width: w,
+            height: h,
+            scale_factor: 1.0,
+            frequency: 60.0,
+            is_primary: false,
+        }
+    }
+
+    #[test]
+    fn parses_valid_edid() {
+        let edid = parse_edid(&sample_edid()).expect("valid EDID should parse");
+        assert_eq!(edid.manufacturer, "ABC");
+        assert_eq!(edid.product_code, 0x1234);
+        assert_eq!(edid.serial_number, 0x0102_0304);
+        assert_eq!(edid.manufacture_week, 10);
+        assert_eq!(edid.manufacture_year, 2023);
+        assert_eq!(edid.edid_version, "1.4");
+        assert_eq!(edid.monitor_name.as_deref(), Some("TestMon"));
+    }
+
+    #[test]
+    fn rejects_bad_header_and_checksum() {
+        let mut bad_header = sample_edid();
+        bad_header[0] = 0x01;
+        assert!(parse_edid(&bad_header).is_err());
+
+        let mut bad_checksum = sample_edid();
+        bad_checksum[127] = bad_checksum[127].wrapping_add(1);
+        assert!(parse_edid(&bad_checksum).is_err());
+    }
+
+    #[test]
+    fn computes_rectangle_overlap() {
+        // Partial overlap: 50x100 region shared.
+        assert_eq!(
+            rect_intersection_area((0, 0, 100, 100), (50, 0, 100, 100)),
+            50 * 100
+        );
+        // Disjoint rectangles overlap by zero.
+        assert_eq!(rect_intersection_area((0, 0, 100, 100), (200, 200, 10, 10)), 0);
+        // Full containment yields the inner rectangle's area.
+        assert_eq!(rect_intersection_area((0, 0, 100, 100), (10, 10, 20, 20)), 400);
+    }
+
+    #[test]
+    fn diffs_snapshots() {
+        let prev = vec![snap("A", 0, 0, 1920, 1080), snap("B", 1920, 0, 1920, 1080)];
+        // B is removed, C added, A resized.
+        let next = vec![
+            snap("A", 0, 0, 2560, 1440),
+            snap("C", 2560, 0, 1920, 1080),
+        ];
+        let (added, removed, modified) = diff_snapshots(&prev, &next);
+        assert_eq!(added, vec!["C".to_string()]);
+        assert_eq!(removed, vec!["B".to_string()]);
+        assert_eq!(modified, vec!["A".to_string()]);
+    }
 }